@@ -1,9 +1,131 @@
 use eval::eval::{Eval, Score};
 use movegen::move_generator::MoveGenerator;
+use movegen::piece::Piece;
+use movegen::position::Position;
 use movegen::position_history::PositionHistory;
 use movegen::r#move::Move;
 use movegen::r#move::MoveList;
 use movegen::side::Side;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Number of killer-move slots to keep per ply.
+const NUM_KILLERS_PER_PLY: usize = 2;
+/// Upper bound on search ply used to size the killer-move table.
+const MAX_PLY: usize = 128;
+
+/// The last `NUM_KILLERS_PER_PLY` quiet moves that caused a beta cutoff at
+/// each ply, tried early in sibling branches since a move that refuted one
+/// line is likely to be strong in another.
+struct KillerMoves {
+    killers: [[Move; NUM_KILLERS_PER_PLY]; MAX_PLY],
+}
+
+impl KillerMoves {
+    fn new() -> Self {
+        KillerMoves {
+            killers: [[Move::NULL; NUM_KILLERS_PER_PLY]; MAX_PLY],
+        }
+    }
+
+    fn is_killer(&self, ply: usize, m: Move) -> bool {
+        self.killers[ply].contains(&m)
+    }
+
+    fn store(&mut self, ply: usize, m: Move) {
+        if self.killers[ply][0] != m {
+            self.killers[ply][1] = self.killers[ply][0];
+            self.killers[ply][0] = m;
+        }
+    }
+}
+
+/// Score of a mate on the current move, high enough to dominate any
+/// material/positional eval. Mates further from the root are reported
+/// closer to zero (offset by `ply`) so the search prefers the shortest one.
+pub const MATE_SCORE: Score = 30_000;
+
+/// Scores with absolute value above this are mate scores — a mate is at
+/// most `MAX_PLY` away, so nothing else gets this close to `MATE_SCORE` —
+/// and need ply-adjustment when moving between the transposition table's
+/// ply-independent storage form and a search node's root-relative form.
+const MATE_THRESHOLD: Score = MATE_SCORE - MAX_PLY as Score;
+
+/// `plies_since_pawn_move_or_capture` at or above this counts as a draw by
+/// the fifty-move rule (the clock is in half-moves, hence 100, not 50).
+const FIFTY_MOVE_RULE_PLIES: u8 = 100;
+
+/// Number of slots in the transposition table. A power of two so the index
+/// can be taken cheaply from the low bits of the Zobrist key.
+const TRANSPOSITION_TABLE_SIZE: usize = 1 << 20;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Bound {
+    Exact,
+    LowerBound,
+    UpperBound,
+}
+
+#[derive(Clone, Copy)]
+struct TranspositionTableEntry {
+    key: u64,
+    depth: usize,
+    score: Score,
+    bound: Bound,
+    best_move: Move,
+}
+
+impl TranspositionTableEntry {
+    const EMPTY: Self = TranspositionTableEntry {
+        key: 0,
+        depth: 0,
+        score: 0,
+        bound: Bound::Exact,
+        best_move: Move::NULL,
+    };
+}
+
+struct TranspositionTable {
+    entries: Vec<TranspositionTableEntry>,
+}
+
+impl TranspositionTable {
+    fn new() -> Self {
+        TranspositionTable {
+            entries: vec![TranspositionTableEntry::EMPTY; TRANSPOSITION_TABLE_SIZE],
+        }
+    }
+
+    fn index(&self, key: u64) -> usize {
+        (key % self.entries.len() as u64) as usize
+    }
+
+    fn probe(&self, key: u64) -> Option<&TranspositionTableEntry> {
+        let entry = &self.entries[self.index(key)];
+        if entry.key == key {
+            Some(entry)
+        } else {
+            None
+        }
+    }
+
+    fn store(
+        &mut self,
+        key: u64,
+        depth: usize,
+        score: Score,
+        bound: Bound,
+        best_move: Move,
+    ) {
+        let idx = self.index(key);
+        self.entries[idx] = TranspositionTableEntry {
+            key,
+            depth,
+            score,
+            bound,
+            best_move,
+        };
+    }
+}
 
 pub struct Search;
 
@@ -20,12 +142,14 @@ impl Search {
                 &mut principal_variation,
                 pos_history,
                 depth,
+                0,
             ),
             Side::Black => -Self::negamax_recursive(
                 &mut move_list_stack,
                 &mut principal_variation,
                 pos_history,
                 depth,
+                0,
             ),
         };
 
@@ -34,7 +158,24 @@ impl Search {
     }
 
     pub fn alpha_beta(pos_history: &mut PositionHistory, depth: usize) -> (Score, MoveList) {
+        let never_stop = AtomicBool::new(false);
+        Self::alpha_beta_abortable(pos_history, depth, &never_stop)
+            .expect("a search that's never asked to stop always completes")
+    }
+
+    /// Like [`Self::alpha_beta`], but checks `stop` throughout the recursion
+    /// and bails out as soon as it's set, returning `None`. Intended for a
+    /// time-managed caller (e.g. a UCI `go` driver doing iterative
+    /// deepening) that flips `stop` once its budget for the current depth
+    /// runs out and falls back to the previous iteration's result.
+    pub fn alpha_beta_abortable(
+        pos_history: &mut PositionHistory,
+        depth: usize,
+        stop: &AtomicBool,
+    ) -> Option<(Score, MoveList)> {
         let mut move_list_stack = vec![MoveList::new(); depth];
+        let mut transposition_table = TranspositionTable::new();
+        let mut killer_moves = KillerMoves::new();
 
         let pv_size = depth * (depth + 1) / 2;
         let mut principal_variation = MoveList::with_capacity(pv_size);
@@ -45,23 +186,163 @@ impl Search {
             Side::White => Self::alpha_beta_recursive(
                 &mut move_list_stack,
                 &mut principal_variation,
+                &mut transposition_table,
+                &mut killer_moves,
                 pos_history,
                 alpha,
                 beta,
                 depth,
+                0,
+                stop,
             ),
             Side::Black => -Self::alpha_beta_recursive(
                 &mut move_list_stack,
                 &mut principal_variation,
+                &mut transposition_table,
+                &mut killer_moves,
                 pos_history,
                 -beta,
                 -alpha,
                 depth,
+                0,
+                stop,
             ),
         };
 
+        if stop.load(Ordering::Relaxed) {
+            return None;
+        }
+
         principal_variation.truncate(depth);
-        (eval, principal_variation)
+        Some((eval, principal_variation))
+    }
+
+    /// Searches incrementally deeper, from depth 1 up to `max_depth`,
+    /// reusing the same transposition table and killer moves across
+    /// iterations: a shallower search's TT entries seed `tt_move` ordering
+    /// at every node of the next, deeper one, so each iteration searches
+    /// the position its predecessor already mapped out first. Calls
+    /// `on_iteration` after every completed depth (e.g. to emit a UCI
+    /// `info` line): depth 1 always completes, deeper iterations only if
+    /// `stop` is still unset when they finish. Returns the last completed
+    /// iteration's result, or `None` if `max_depth` is 0.
+    pub fn iterative_deepening(
+        pos_history: &mut PositionHistory,
+        max_depth: usize,
+        stop: &AtomicBool,
+        mut on_iteration: impl FnMut(usize, Score, &MoveList),
+    ) -> Option<(Score, MoveList)> {
+        let mut transposition_table = TranspositionTable::new();
+        let mut killer_moves = KillerMoves::new();
+        let mut best = None;
+        // Under a very tight time budget `stop` can already be set before
+        // depth 1 even finishes; searching depth 1 against it regardless
+        // would abort mid-tree and return an unreliable fail-low score
+        // instead of a real move. So depth 1 always runs to completion
+        // against a flag that's never set, guaranteeing the caller gets at
+        // least one legal move back rather than nothing.
+        let never_stop = AtomicBool::new(false);
+
+        for depth in 1..=max_depth {
+            let depth_stop = if depth == 1 { &never_stop } else { stop };
+            let mut move_list_stack = vec![MoveList::new(); depth];
+            let pv_size = depth * (depth + 1) / 2;
+            let mut principal_variation = MoveList::with_capacity(pv_size);
+            principal_variation.resize(pv_size, Move::NULL);
+            let alpha = Score::MIN + 1;
+            let beta = Score::MAX;
+
+            let eval = match pos_history.current_pos().side_to_move() {
+                Side::White => Self::alpha_beta_recursive(
+                    &mut move_list_stack,
+                    &mut principal_variation,
+                    &mut transposition_table,
+                    &mut killer_moves,
+                    pos_history,
+                    alpha,
+                    beta,
+                    depth,
+                    0,
+                    depth_stop,
+                ),
+                Side::Black => -Self::alpha_beta_recursive(
+                    &mut move_list_stack,
+                    &mut principal_variation,
+                    &mut transposition_table,
+                    &mut killer_moves,
+                    pos_history,
+                    -beta,
+                    -alpha,
+                    depth,
+                    0,
+                    depth_stop,
+                ),
+            };
+
+            if depth > 1 && stop.load(Ordering::Relaxed) {
+                break;
+            }
+
+            principal_variation.truncate(depth);
+            on_iteration(depth, eval, &principal_variation);
+            best = Some((eval, principal_variation));
+
+            if stop.load(Ordering::Relaxed) {
+                break;
+            }
+        }
+
+        best
+    }
+
+    /// If `score` (in the same side-to-move-relative convention as a UCI
+    /// `score` field) represents a forced mate, the number of moves until
+    /// it lands — positive if the side to move delivers it, negative if
+    /// it is delivered against them. `None` for any other score.
+    pub fn mate_distance_in_moves(score: Score) -> Option<i32> {
+        const MATE_THRESHOLD: Score = MATE_SCORE - MAX_PLY as Score;
+        if score.abs() <= MATE_THRESHOLD {
+            return None;
+        }
+        let plies_to_mate = MATE_SCORE - score.abs();
+        let moves_to_mate = (plies_to_mate as i32 + 1) / 2;
+        Some(if score > 0 { moves_to_mate } else { -moves_to_mate })
+    }
+
+    /// True if the current position is a draw by a rule the engine can
+    /// decide without searching: the fifty-move rule, or threefold
+    /// repetition since the last irreversible (pawn move or capture) move.
+    fn is_draw(pos_history: &PositionHistory) -> bool {
+        pos_history.current_pos().plies_since_pawn_move_or_capture() >= FIFTY_MOVE_RULE_PLIES
+            || Self::is_threefold_repetition(pos_history)
+    }
+
+    fn is_threefold_repetition(pos_history: &PositionHistory) -> bool {
+        let plies = pos_history.plies();
+        let current = match plies.last() {
+            Some(pos) => pos,
+            None => return false,
+        };
+
+        let irreversible_plies = current.plies_since_pawn_move_or_capture() as usize;
+        let search_start = plies.len().saturating_sub(irreversible_plies + 1);
+        let earlier_plies = &plies[search_start..plies.len() - 1];
+        let repetitions = earlier_plies
+            .iter()
+            .filter(|pos| pos.zobrist_key() == current.zobrist_key())
+            .count();
+        repetitions >= 2
+    }
+
+    /// Score of a position with no legal moves: a checkmate (as bad as
+    /// possible for the side to move, but shorter mates score closer to
+    /// `-MATE_SCORE` than longer ones) or a stalemate (a draw).
+    fn terminal_score(pos_history: &PositionHistory, ply: usize) -> Score {
+        if MoveGenerator::is_in_check(pos_history.current_pos()) {
+            -(MATE_SCORE - ply as Score)
+        } else {
+            0
+        }
     }
 
     fn negamax_recursive(
@@ -69,16 +350,23 @@ impl Search {
         principal_variation: &mut MoveList,
         pos_history: &mut PositionHistory,
         depth: usize,
+        ply: usize,
     ) -> Score {
+        if Self::is_draw(pos_history) {
+            return 0;
+        }
+
         let mut max = Score::MIN;
 
-        // TODO Also check terminal nodes
         match depth {
-            0 => max = Eval::eval_relative(pos_history.current_pos()),
+            0 => max = Self::quiescence(move_list_stack, pos_history, Score::MIN + 1, Score::MAX),
             _ => {
                 debug_assert!(!move_list_stack.is_empty());
                 let mut move_list = move_list_stack.pop().unwrap();
                 MoveGenerator::generate_moves(&mut move_list, pos_history.current_pos());
+                if move_list.is_empty() {
+                    max = Self::terminal_score(pos_history, ply);
+                }
                 for m in move_list.iter() {
                     pos_history.do_move(*m);
                     let new_score = -Self::negamax_recursive(
@@ -86,6 +374,7 @@ impl Search {
                         principal_variation,
                         pos_history,
                         depth - 1,
+                        ply + 1,
                     );
                     if new_score > max {
                         max = new_score;
@@ -106,42 +395,88 @@ impl Search {
         max
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn alpha_beta_recursive(
         move_list_stack: &mut Vec<MoveList>,
         principal_variation: &mut MoveList,
+        transposition_table: &mut TranspositionTable,
+        killer_moves: &mut KillerMoves,
         pos_history: &mut PositionHistory,
         mut alpha: Score,
         beta: Score,
         depth: usize,
+        ply: usize,
+        stop: &AtomicBool,
     ) -> Score {
+        if stop.load(Ordering::Relaxed) {
+            return alpha;
+        }
+        if Self::is_draw(pos_history) {
+            return 0;
+        }
+
+        let original_alpha = alpha;
+        let zobrist_key = pos_history.current_pos().zobrist_key();
+        let mut tt_move = Move::NULL;
+        if let Some(entry) = transposition_table.probe(zobrist_key) {
+            tt_move = entry.best_move;
+            if entry.depth >= depth {
+                let entry_score = Self::score_from_tt(entry.score, ply);
+                match entry.bound {
+                    Bound::Exact => return entry_score,
+                    Bound::LowerBound if entry_score >= beta => return entry_score,
+                    Bound::UpperBound if entry_score <= alpha => return entry_score,
+                    _ => {}
+                }
+            }
+        }
+
         let mut score = Score::MIN + 1;
+        let mut best_move = Move::NULL;
 
-        // TODO Also check terminal nodes
         match depth {
-            0 => score = Eval::eval_relative(pos_history.current_pos()),
+            0 => score = Self::quiescence(move_list_stack, pos_history, alpha, beta),
             _ => {
                 debug_assert!(!move_list_stack.is_empty());
                 let mut move_list = move_list_stack.pop().unwrap();
                 MoveGenerator::generate_moves(&mut move_list, pos_history.current_pos());
+                if move_list.is_empty() {
+                    score = Self::terminal_score(pos_history, ply);
+                }
+                Self::order_moves(
+                    &mut move_list,
+                    pos_history.current_pos(),
+                    tt_move,
+                    killer_moves,
+                    ply,
+                );
                 for m in move_list.iter() {
                     pos_history.do_move(*m);
                     let new_score = -Self::alpha_beta_recursive(
                         move_list_stack,
                         principal_variation,
+                        transposition_table,
+                        killer_moves,
                         pos_history,
                         -beta,
                         -alpha,
                         depth - 1,
+                        ply + 1,
+                        stop,
                     );
                     if new_score >= beta {
                         score = beta;
+                        best_move = *m;
                         pos_history.undo_last_move();
+                        if !m.is_capture() {
+                            killer_moves.store(ply, *m);
+                        }
                         break;
                     }
                     if new_score > alpha {
                         alpha = new_score;
                         score = new_score;
-                        let best_move = *m;
+                        best_move = *m;
 
                         let dist_from_end = depth * (depth + 1) / 2;
                         let idx = principal_variation.len() - dist_from_end;
@@ -151,10 +486,180 @@ impl Search {
                         }
                     }
                     pos_history.undo_last_move();
+                    if stop.load(Ordering::Relaxed) {
+                        break;
+                    }
                 }
                 move_list_stack.push(move_list);
             }
         }
+
+        let bound = if score <= original_alpha {
+            Bound::UpperBound
+        } else if score >= beta {
+            Bound::LowerBound
+        } else {
+            Bound::Exact
+        };
+        transposition_table.store(zobrist_key, depth, Self::score_to_tt(score, ply), bound, best_move);
+
+        score
+    }
+
+    /// Converts a root-relative score (as used during search, comparable
+    /// against `alpha`/`beta` at any node) into the ply-independent form
+    /// stored in the transposition table: a mate score is moved to count
+    /// plies from the node it's stored at rather than from the root, so a
+    /// later probe of the same entry at a different ply can convert it back
+    /// to that probe's own root-relative value via [`Self::score_from_tt`].
+    fn score_to_tt(score: Score, ply: usize) -> Score {
+        if score > MATE_THRESHOLD {
+            score + ply as Score
+        } else if score < -MATE_THRESHOLD {
+            score - ply as Score
+        } else {
+            score
+        }
+    }
+
+    /// Inverse of [`Self::score_to_tt`]: converts a ply-independent score
+    /// read back out of the transposition table into a value that's
+    /// root-relative for the probing node's `ply`.
+    fn score_from_tt(score: Score, ply: usize) -> Score {
+        if score > MATE_THRESHOLD {
+            score - ply as Score
+        } else if score < -MATE_THRESHOLD {
+            score + ply as Score
+        } else {
+            score
+        }
+    }
+
+    /// Orders `move_list` so that cutoffs are found as early as possible:
+    /// the transposition-table move from an earlier, shallower search of
+    /// this same position first, then captures ranked by MVV-LVA
+    /// (most-valuable-victim / least-valuable-attacker), then the killer
+    /// quiet moves that caused a beta cutoff at this ply in a sibling
+    /// branch, then everything else in generation order.
+    fn order_moves(
+        move_list: &mut MoveList,
+        pos: &Position,
+        tt_move: Move,
+        killer_moves: &KillerMoves,
+        ply: usize,
+    ) {
+        move_list
+            .sort_by_key(|m| std::cmp::Reverse(Self::move_order_score(pos, *m, tt_move, killer_moves, ply)));
+    }
+
+    fn move_order_score(
+        pos: &Position,
+        m: Move,
+        tt_move: Move,
+        killer_moves: &KillerMoves,
+        ply: usize,
+    ) -> i32 {
+        const TT_MOVE_SCORE: i32 = i32::MAX;
+        const KILLER_SCORE: i32 = 1_000;
+
+        if m == tt_move {
+            return TT_MOVE_SCORE;
+        }
+        if m.is_capture() {
+            // En passant captures land on an empty square — the captured
+            // pawn actually sits on `m.from()`'s rank — so `piece_at(m.to())`
+            // would otherwise see no victim there and under-score the move.
+            let victim_value = match pos.piece_at(m.to()) {
+                Some(victim) => Self::piece_value(victim),
+                None => Self::piece_value(Piece::WhitePawn),
+            };
+            let attacker_rank = pos.piece_at(m.from()).map_or(0, Self::attacker_rank);
+            return victim_value * 16 - attacker_rank;
+        }
+        if killer_moves.is_killer(ply, m) {
+            return KILLER_SCORE;
+        }
+        0
+    }
+
+    fn piece_value(piece: Piece) -> i32 {
+        match piece {
+            Piece::WhitePawn | Piece::BlackPawn => 100,
+            Piece::WhiteKnight | Piece::BlackKnight => 320,
+            Piece::WhiteBishop | Piece::BlackBishop => 330,
+            Piece::WhiteRook | Piece::BlackRook => 500,
+            Piece::WhiteQueen | Piece::BlackQueen => 900,
+            Piece::WhiteKing | Piece::BlackKing => 20_000,
+        }
+    }
+
+    /// Attacker rank for MVV-LVA, 1 (pawn) through 6 (king): small enough
+    /// that the attacker term can never outweigh the victim term, so every
+    /// capture still outranks the quiet/killer-move scores regardless of
+    /// which piece is doing the capturing.
+    fn attacker_rank(piece: Piece) -> i32 {
+        match piece {
+            Piece::WhitePawn | Piece::BlackPawn => 1,
+            Piece::WhiteKnight | Piece::BlackKnight => 2,
+            Piece::WhiteBishop | Piece::BlackBishop => 3,
+            Piece::WhiteRook | Piece::BlackRook => 4,
+            Piece::WhiteQueen | Piece::BlackQueen => 5,
+            Piece::WhiteKing | Piece::BlackKing => 6,
+        }
+    }
+
+    /// Resolves the horizon effect at leaf nodes by playing out capture
+    /// sequences instead of taking the static eval at face value. Starts
+    /// from a "stand-pat" score — the side to move is never forced to
+    /// capture, so if simply standing still already fails high or raises
+    /// alpha, that's used as the baseline — then searches captures only,
+    /// which bounds the recursion by their natural exhaustion.
+    ///
+    /// Skips the stand-pat baseline entirely when the side to move is in
+    /// check: the static eval doesn't account for check, so standing pat
+    /// there could score a position that is actually being mated as if it
+    /// were merely quiet.
+    fn quiescence(
+        move_list_stack: &mut Vec<MoveList>,
+        pos_history: &mut PositionHistory,
+        mut alpha: Score,
+        beta: Score,
+    ) -> Score {
+        let in_check = MoveGenerator::is_in_check(pos_history.current_pos());
+        let mut score = alpha;
+        if !in_check {
+            let stand_pat = Eval::eval_relative(pos_history.current_pos());
+            if stand_pat >= beta {
+                return beta;
+            }
+            if stand_pat > alpha {
+                alpha = stand_pat;
+            }
+            score = alpha;
+        }
+
+        let mut move_list = move_list_stack.pop().unwrap_or_else(MoveList::new);
+        MoveGenerator::generate_moves(&mut move_list, pos_history.current_pos());
+        // In check, a capture-only search can miss every legal reply (a king
+        // step or a block), leaving nothing to search and failing low to
+        // `alpha` instead of refuting the check. So search all evasions, not
+        // just captures, whenever we're in check.
+        for m in move_list.iter().filter(|m| in_check || m.is_capture()) {
+            pos_history.do_move(*m);
+            let new_score = -Self::quiescence(move_list_stack, pos_history, -beta, -alpha);
+            pos_history.undo_last_move();
+
+            if new_score >= beta {
+                score = beta;
+                break;
+            }
+            if new_score > alpha {
+                alpha = new_score;
+                score = new_score;
+            }
+        }
+        move_list_stack.push(move_list);
+
         score
     }
 }
@@ -165,6 +670,29 @@ mod tests {
     use movegen::position::Position;
     use movegen::position_history::PositionHistory;
 
+    /// `negamax`/`alpha_beta` evaluate leaf nodes through `quiescence` now,
+    /// so the leaf reached by replaying the PV is no longer guaranteed quiet
+    /// — `quiescence` may play on through captures beyond it. The invariant
+    /// a PV must satisfy is therefore that the returned score matches
+    /// `quiescence` run on the leaf, converted from relative to absolute the
+    /// same way the root of the search does.
+    fn quiescence_at_leaf(pos_history: &mut PositionHistory) -> Score {
+        match pos_history.current_pos().side_to_move() {
+            Side::White => Search::quiescence(
+                &mut Vec::new(),
+                pos_history,
+                Score::MIN + 1,
+                Score::MAX,
+            ),
+            Side::Black => -Search::quiescence(
+                &mut Vec::new(),
+                pos_history,
+                Score::MIN + 1,
+                Score::MAX,
+            ),
+        }
+    }
+
     #[test]
     fn negamax() {
         let mut pos_history = PositionHistory::new(Position::initial());
@@ -175,7 +703,7 @@ mod tests {
             for m in pv.iter() {
                 pos_history.do_move(*m);
             }
-            assert_eq!(Eval::eval(pos_history.current_pos()), score);
+            assert_eq!(quiescence_at_leaf(&mut pos_history), score);
             for _ in 0..depth {
                 pos_history.undo_last_move();
             }
@@ -192,10 +720,40 @@ mod tests {
             for m in pv.iter() {
                 pos_history.do_move(*m);
             }
-            assert_eq!(Eval::eval(pos_history.current_pos()), score);
+            assert_eq!(quiescence_at_leaf(&mut pos_history), score);
             for _ in 0..depth {
                 pos_history.undo_last_move();
             }
         }
     }
+
+    #[test]
+    fn alpha_beta_scores_checkmate() {
+        // Scholar's mate: 1. e4 e5 2. Qh5 Nc6 3. Bc4 Nf6 4. Qxf7#
+        let fen = "r1bqkb1r/pppp1Qpp/2n2n2/4p3/2B1P3/8/PPPP1PPP/RNB1K1NR b KQkq - 0 4";
+        let mut pos_history = PositionHistory::new(Position::from_fen(fen).unwrap());
+
+        let (score, _) = Search::alpha_beta(&mut pos_history, 1);
+        assert_eq!(MATE_SCORE, score);
+    }
+
+    #[test]
+    fn alpha_beta_scores_stalemate() {
+        let fen = "7k/5Q2/6K1/8/8/8/8/8 b - - 0 1";
+        let mut pos_history = PositionHistory::new(Position::from_fen(fen).unwrap());
+
+        let (score, _) = Search::alpha_beta(&mut pos_history, 1);
+        assert_eq!(0, score);
+    }
+
+    #[test]
+    fn alpha_beta_claims_fifty_move_rule_draw() {
+        // White is up a whole queen, but the halfmove clock is already at
+        // the fifty-move limit, so the position must be scored as a draw.
+        let fen = "4k3/8/8/8/8/8/8/4KQ2 w - - 100 60";
+        let mut pos_history = PositionHistory::new(Position::from_fen(fen).unwrap());
+
+        let (score, _) = Search::alpha_beta(&mut pos_history, 2);
+        assert_eq!(0, score);
+    }
 }
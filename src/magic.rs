@@ -0,0 +1,227 @@
+//! Magic bitboard attack generation for sliding pieces (rooks, bishops and,
+//! by composition, queens).
+//!
+//! For each square we precompute a relevant-occupancy mask (the squares a
+//! slider's rays pass over, excluding the board edge, since a blocker there
+//! never changes the attack set) together with a magic multiplier and a
+//! shift that hash any occupancy subset of that mask onto a dense attack
+//! table. Two subsets may share a slot only if their attack sets are
+//! equal. Lookups are then branchless:
+//! `attacks[((occupancy & mask).0.wrapping_mul(magic) >> shift) as usize]`.
+
+use crate::bitboard::Bitboard;
+use std::sync::OnceLock;
+
+const NUM_SQUARES: usize = 64;
+
+const ROOK_DELTAS: [(i8, i8); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+const BISHOP_DELTAS: [(i8, i8); 4] = [(1, 1), (1, -1), (-1, 1), (-1, -1)];
+
+struct MagicEntry {
+    mask: Bitboard,
+    magic: u64,
+    shift: u32,
+    attacks: Vec<Bitboard>,
+}
+
+impl MagicEntry {
+    fn index(&self, occupancy: Bitboard) -> usize {
+        let relevant = occupancy & self.mask;
+        ((relevant.0.wrapping_mul(self.magic)) >> self.shift) as usize
+    }
+
+    fn attacks(&self, occupancy: Bitboard) -> Bitboard {
+        self.attacks[self.index(occupancy)]
+    }
+}
+
+struct MagicTables {
+    rook: Vec<MagicEntry>,
+    bishop: Vec<MagicEntry>,
+}
+
+impl MagicTables {
+    fn generate() -> Self {
+        let rook = (0..NUM_SQUARES)
+            .map(|square| build_magic_entry(square, &ROOK_DELTAS))
+            .collect();
+        let bishop = (0..NUM_SQUARES)
+            .map(|square| build_magic_entry(square, &BISHOP_DELTAS))
+            .collect();
+        MagicTables { rook, bishop }
+    }
+
+    fn get() -> &'static Self {
+        static TABLES: OnceLock<MagicTables> = OnceLock::new();
+        TABLES.get_or_init(Self::generate)
+    }
+}
+
+/// Squares a slider moving along `deltas` can reach from `square` with the
+/// board otherwise empty, excluding the final square of each ray: a blocker
+/// standing on the edge never removes a square from the attack set, so it is
+/// irrelevant to the hash and leaving it out keeps the mask (and therefore
+/// the table) as small as possible.
+fn relevant_occupancy_mask(square: usize, deltas: &[(i8, i8)]) -> Bitboard {
+    let (rank, file) = to_rank_file(square);
+    let mut mask = Bitboard::EMPTY;
+    for &(dr, df) in deltas {
+        let (mut r, mut f) = (rank + dr, file + df);
+        while in_bounds(r + dr, f + df) {
+            mask = mask | square_bb(r, f);
+            r += dr;
+            f += df;
+        }
+    }
+    mask
+}
+
+/// The true attack set of a slider moving along `deltas` from `square` given
+/// a concrete board `occupancy`, stopping at (and including) the first
+/// blocker in each direction.
+fn sliding_attacks(square: usize, occupancy: Bitboard, deltas: &[(i8, i8)]) -> Bitboard {
+    let (rank, file) = to_rank_file(square);
+    let mut attacks = Bitboard::EMPTY;
+    for &(dr, df) in deltas {
+        let (mut r, mut f) = (rank + dr, file + df);
+        while in_bounds(r, f) {
+            let square_bb = square_bb(r, f);
+            attacks = attacks | square_bb;
+            if occupancy & square_bb != Bitboard::EMPTY {
+                break;
+            }
+            r += dr;
+            f += df;
+        }
+    }
+    attacks
+}
+
+fn build_magic_entry(square: usize, deltas: &[(i8, i8)]) -> MagicEntry {
+    let mask = relevant_occupancy_mask(square, deltas);
+    let relevant_bits = mask.0.count_ones();
+    let shift = 64 - relevant_bits;
+    let table_size = 1usize << relevant_bits;
+
+    let occupancies_and_attacks: Vec<(Bitboard, Bitboard)> = subsets(mask)
+        .map(|occupancy| (occupancy, sliding_attacks(square, occupancy, deltas)))
+        .collect();
+
+    let mut rng = SplitMix64(0x9e37_79b9_7f4a_7c15 ^ square as u64);
+    'search: loop {
+        let magic = sparse_random_u64(&mut rng);
+        let mut attacks = vec![Bitboard::EMPTY; table_size];
+        let mut used = vec![false; table_size];
+        for &(occupancy, attack) in &occupancies_and_attacks {
+            let index = ((occupancy & mask).0.wrapping_mul(magic) >> shift) as usize;
+            if used[index] && attacks[index] != attack {
+                continue 'search;
+            }
+            used[index] = true;
+            attacks[index] = attack;
+        }
+        return MagicEntry {
+            mask,
+            magic,
+            shift,
+            attacks,
+        };
+    }
+}
+
+/// Enumerates every subset of `mask` via the carry-rippler trick, including
+/// the empty subset.
+fn subsets(mask: Bitboard) -> impl Iterator<Item = Bitboard> {
+    let mut subset = 0u64;
+    let mut done = false;
+    std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        let current = Bitboard(subset);
+        subset = subset.wrapping_sub(mask.0) & mask.0;
+        done = subset == 0;
+        Some(current)
+    })
+}
+
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+}
+
+/// Sparsely populated random candidates collide far less often than uniform
+/// ones, which is why magic-number search ANDs a few random draws together.
+fn sparse_random_u64(rng: &mut SplitMix64) -> u64 {
+    rng.next() & rng.next() & rng.next()
+}
+
+fn to_rank_file(square: usize) -> (i8, i8) {
+    (
+        (square / Bitboard::NUM_FILES) as i8,
+        (square % Bitboard::NUM_FILES) as i8,
+    )
+}
+
+fn in_bounds(rank: i8, file: i8) -> bool {
+    (0..Bitboard::NUM_RANKS as i8).contains(&rank) && (0..Bitboard::NUM_FILES as i8).contains(&file)
+}
+
+fn square_bb(rank: i8, file: i8) -> Bitboard {
+    Bitboard(0x1 << Bitboard::to_square(rank as usize, file as usize))
+}
+
+/// Rook attacks from `square` given the current board `occupancy`.
+pub fn rook_attacks(square: usize, occupancy: Bitboard) -> Bitboard {
+    MagicTables::get().rook[square].attacks(occupancy)
+}
+
+/// Bishop attacks from `square` given the current board `occupancy`.
+pub fn bishop_attacks(square: usize, occupancy: Bitboard) -> Bitboard {
+    MagicTables::get().bishop[square].attacks(occupancy)
+}
+
+/// Queen attacks from `square`: the union of the rook and bishop rays.
+pub fn queen_attacks(square: usize, occupancy: Bitboard) -> Bitboard {
+    rook_attacks(square, occupancy) | bishop_attacks(square, occupancy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rook_attacks_on_empty_board_cover_full_rank_and_file() {
+        let attacks = rook_attacks(Bitboard::IDX_D4, Bitboard::EMPTY);
+        assert_eq!(14, attacks.0.count_ones());
+    }
+
+    #[test]
+    fn rook_attacks_stop_at_first_blocker() {
+        let occupancy = Bitboard(0x1 << Bitboard::IDX_D6);
+        let attacks = rook_attacks(Bitboard::IDX_D4, occupancy);
+        assert_ne!(Bitboard::EMPTY, attacks & occupancy);
+        assert_eq!(Bitboard::EMPTY, attacks & Bitboard(0x1 << Bitboard::IDX_D7));
+    }
+
+    #[test]
+    fn bishop_attacks_on_empty_board_cover_both_diagonals() {
+        let attacks = bishop_attacks(Bitboard::IDX_D4, Bitboard::EMPTY);
+        assert_eq!(13, attacks.0.count_ones());
+    }
+
+    #[test]
+    fn queen_attacks_are_the_union_of_rook_and_bishop_attacks() {
+        let occupancy = Bitboard::EMPTY;
+        let expected =
+            rook_attacks(Bitboard::IDX_D4, occupancy) | bishop_attacks(Bitboard::IDX_D4, occupancy);
+        assert_eq!(expected, queen_attacks(Bitboard::IDX_D4, occupancy));
+    }
+}
@@ -1,7 +1,9 @@
 use crate::bitboard::Bitboard;
 use crate::piece::Piece;
+use std::error;
 use std::fmt;
 use std::str;
+use std::sync::OnceLock;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum SideToMove {
@@ -21,6 +23,128 @@ bitflags! {
     }
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub enum FenError {
+    WrongFieldCount(usize),
+    WrongRankCount(usize),
+    WrongFileCount(usize, String),
+    InvalidPiece(char),
+    InvalidSideToMove(String),
+    InvalidCastlingRights(String),
+    InvalidEnPassantSquare(String),
+    InvalidHalfmoveClock(String),
+    InvalidFullmoveCounter(String),
+    MissingKing(SideToMove),
+    MultipleKings(SideToMove),
+    PawnOnBackRank,
+}
+
+impl fmt::Display for FenError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FenError::WrongFieldCount(count) => {
+                write!(f, "expected 6 space-separated fields, found {}", count)
+            }
+            FenError::WrongRankCount(count) => {
+                write!(f, "expected 8 ranks in piece placement, found {}", count)
+            }
+            FenError::WrongFileCount(count, rank_str) => write!(
+                f,
+                "expected 8 files in rank, found {} in \"{}\"",
+                count, rank_str
+            ),
+            FenError::InvalidPiece(c) => write!(f, "invalid piece character '{}'", c),
+            FenError::InvalidSideToMove(s) => write!(f, "invalid side to move \"{}\"", s),
+            FenError::InvalidCastlingRights(s) => write!(f, "invalid castling rights \"{}\"", s),
+            FenError::InvalidEnPassantSquare(s) => {
+                write!(f, "invalid en passant square \"{}\"", s)
+            }
+            FenError::InvalidHalfmoveClock(s) => write!(f, "invalid halfmove clock \"{}\"", s),
+            FenError::InvalidFullmoveCounter(s) => {
+                write!(f, "invalid fullmove counter \"{}\"", s)
+            }
+            FenError::MissingKing(side) => write!(f, "missing king for {:?}", side),
+            FenError::MultipleKings(side) => write!(f, "more than one king for {:?}", side),
+            FenError::PawnOnBackRank => write!(f, "pawn on rank 1 or rank 8"),
+        }
+    }
+}
+
+impl error::Error for FenError {}
+
+const NUM_PIECE_KINDS: usize = 12;
+const NUM_CASTLING_RIGHTS: usize = 4;
+const NUM_FILES: usize = 8;
+
+struct ZobristKeys {
+    piece_square: [[u64; 64]; NUM_PIECE_KINDS],
+    side_to_move: u64,
+    castling_rights: [u64; NUM_CASTLING_RIGHTS],
+    en_passant_file: [u64; NUM_FILES],
+}
+
+/// A fast, deterministic pseudo-random number generator used only to seed
+/// the Zobrist key table. Not suitable for anything security-sensitive.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+}
+
+impl ZobristKeys {
+    fn generate() -> Self {
+        let mut rng = SplitMix64(0x5eed_dead_beef_cafe);
+        let mut piece_square = [[0u64; 64]; NUM_PIECE_KINDS];
+        for piece_keys in piece_square.iter_mut() {
+            for key in piece_keys.iter_mut() {
+                *key = rng.next();
+            }
+        }
+        let side_to_move = rng.next();
+        let mut castling_rights = [0u64; NUM_CASTLING_RIGHTS];
+        for key in castling_rights.iter_mut() {
+            *key = rng.next();
+        }
+        let mut en_passant_file = [0u64; NUM_FILES];
+        for key in en_passant_file.iter_mut() {
+            *key = rng.next();
+        }
+        ZobristKeys {
+            piece_square,
+            side_to_move,
+            castling_rights,
+            en_passant_file,
+        }
+    }
+
+    fn get() -> &'static Self {
+        static KEYS: OnceLock<ZobristKeys> = OnceLock::new();
+        KEYS.get_or_init(Self::generate)
+    }
+
+    fn piece_key(piece: Piece, square: usize) -> u64 {
+        Self::get().piece_square[piece as usize][square]
+    }
+
+    fn side_to_move_key() -> u64 {
+        Self::get().side_to_move
+    }
+
+    fn castling_right_key(bit_index: usize) -> u64 {
+        Self::get().castling_rights[bit_index]
+    }
+
+    fn en_passant_file_key(file: usize) -> u64 {
+        Self::get().en_passant_file[file]
+    }
+}
+
 pub struct Position {
     pawns: Bitboard,
     knights: Bitboard,
@@ -35,11 +159,12 @@ pub struct Position {
     castling_rights: CastlingRights,
     plies_since_pawn_move_or_capture: u8,
     move_count: u16,
+    zobrist: u64,
 }
 
 impl Position {
     pub fn initial() -> Self {
-        Position {
+        let mut pos = Position {
             pawns: Bitboard::RANK_2 | Bitboard::RANK_7,
             knights: Bitboard::B1 | Bitboard::G1 | Bitboard::B8 | Bitboard::G8,
             bishops: Bitboard::C1 | Bitboard::F1 | Bitboard::C8 | Bitboard::F8,
@@ -53,6 +178,80 @@ impl Position {
             castling_rights: CastlingRights::WHITE_BOTH | CastlingRights::BLACK_BOTH,
             plies_since_pawn_move_or_capture: 0,
             move_count: 1,
+            zobrist: 0,
+        };
+        pos.zobrist = pos.compute_zobrist_key();
+        pos
+    }
+
+    /// The current Zobrist key of the position. Equal positions (reached via
+    /// different move orders) share the same key, which is how
+    /// transposition-table lookups and repetition detection work.
+    pub fn zobrist_key(&self) -> u64 {
+        self.zobrist
+    }
+
+    /// Halfmove clock: plies since the last pawn move or capture. Used to
+    /// enforce the fifty-move rule and to bound how far back threefold
+    /// repetition needs to scan (a pawn move or capture is irreversible, so
+    /// no repetition can reach past it).
+    pub fn plies_since_pawn_move_or_capture(&self) -> u8 {
+        self.plies_since_pawn_move_or_capture
+    }
+
+    fn compute_zobrist_key(&self) -> u64 {
+        let mut key = 0u64;
+        for square in 0..Bitboard::NUM_RANKS * Bitboard::NUM_FILES {
+            if let Some(piece) = self.piece_at(square) {
+                key ^= ZobristKeys::piece_key(piece, square);
+            }
+        }
+        if self.side_to_move == SideToMove::Black {
+            key ^= ZobristKeys::side_to_move_key();
+        }
+        for bit_index in 0..NUM_CASTLING_RIGHTS {
+            if self.castling_rights.bits & (1 << bit_index) != 0 {
+                key ^= ZobristKeys::castling_right_key(bit_index);
+            }
+        }
+        if self.en_passant_square != Bitboard::EMPTY {
+            let square = self.en_passant_square.0.trailing_zeros() as usize;
+            key ^= ZobristKeys::en_passant_file_key(square % Bitboard::NUM_FILES);
+        }
+        key
+    }
+
+    /// XORs the key of `piece` standing on `square` into `self.zobrist`.
+    /// XOR is its own inverse, so a second call with the same arguments
+    /// restores the previous key.
+    ///
+    /// Each `toggle_*` method flips one component of the Zobrist key:
+    /// `toggle_piece_key` one piece on one square, `toggle_side_to_move_key`
+    /// the side to move, `toggle_castling_rights_key` each of the given
+    /// rights bits, and `toggle_en_passant_square_key` the file of the given
+    /// en passant square (if any). Otherwise `self.zobrist` is only set from
+    /// scratch, by `initial` and `from_fen`; nothing in this file calls these
+    /// methods when a move is made or undone.
+    pub(crate) fn toggle_piece_key(&mut self, piece: Piece, square: usize) {
+        self.zobrist ^= ZobristKeys::piece_key(piece, square);
+    }
+
+    pub(crate) fn toggle_side_to_move_key(&mut self) {
+        self.zobrist ^= ZobristKeys::side_to_move_key();
+    }
+
+    pub(crate) fn toggle_castling_rights_key(&mut self, changed_rights: CastlingRights) {
+        for bit_index in 0..NUM_CASTLING_RIGHTS {
+            if changed_rights.bits & (1 << bit_index) != 0 {
+                self.zobrist ^= ZobristKeys::castling_right_key(bit_index);
+            }
+        }
+    }
+
+    pub(crate) fn toggle_en_passant_square_key(&mut self, en_passant_square: Bitboard) {
+        if en_passant_square != Bitboard::EMPTY {
+            let square = en_passant_square.0.trailing_zeros() as usize;
+            self.zobrist ^= ZobristKeys::en_passant_file_key(square % Bitboard::NUM_FILES);
         }
     }
 
@@ -92,6 +291,255 @@ impl Position {
             None
         }
     }
+
+    /// Parses a position from Forsyth-Edwards Notation.
+    ///
+    /// All six fields (piece placement, active color, castling availability,
+    /// en passant target square, halfmove clock, fullmove number) must be
+    /// present. Returns a [`FenError`] instead of panicking if the string is
+    /// malformed, if either side has no king or more than one, or if a pawn
+    /// stands on rank 1 or rank 8. No other legality checks are made.
+    pub fn from_fen(fen: &str) -> Result<Self, FenError> {
+        let fields: Vec<&str> = fen.split_whitespace().collect();
+        if fields.len() != 6 {
+            return Err(FenError::WrongFieldCount(fields.len()));
+        }
+
+        let mut pos = Position {
+            pawns: Bitboard::EMPTY,
+            knights: Bitboard::EMPTY,
+            bishops: Bitboard::EMPTY,
+            rooks: Bitboard::EMPTY,
+            queens: Bitboard::EMPTY,
+            kings: Bitboard::EMPTY,
+            white_pieces: Bitboard::EMPTY,
+            black_pieces: Bitboard::EMPTY,
+            en_passant_square: Bitboard::EMPTY,
+            side_to_move: SideToMove::White,
+            castling_rights: CastlingRights::empty(),
+            plies_since_pawn_move_or_capture: 0,
+            move_count: 1,
+            zobrist: 0,
+        };
+
+        Self::parse_piece_placement(&mut pos, fields[0])?;
+        pos.side_to_move = Self::parse_side_to_move(fields[1])?;
+        pos.castling_rights = Self::parse_castling_rights(fields[2])?;
+        pos.en_passant_square = Self::parse_en_passant_square(fields[3])?;
+        pos.plies_since_pawn_move_or_capture = fields[4]
+            .parse()
+            .map_err(|_| FenError::InvalidHalfmoveClock(fields[4].to_owned()))?;
+        pos.move_count = fields[5]
+            .parse()
+            .map_err(|_| FenError::InvalidFullmoveCounter(fields[5].to_owned()))?;
+
+        pos.validate()?;
+        pos.zobrist = pos.compute_zobrist_key();
+        Ok(pos)
+    }
+
+    /// Serializes the position to Forsyth-Edwards Notation.
+    pub fn to_fen(&self) -> String {
+        let mut fen = String::new();
+
+        for rank in (0..Bitboard::NUM_RANKS).rev() {
+            let mut empty_squares = 0;
+            for file in 0..Bitboard::NUM_FILES {
+                let square = Bitboard::to_square(rank, file);
+                match self.piece_at(square) {
+                    None => empty_squares += 1,
+                    Some(piece) => {
+                        if empty_squares > 0 {
+                            fen.push_str(&empty_squares.to_string());
+                            empty_squares = 0;
+                        }
+                        fen.push(piece.to_ascii() as char);
+                    }
+                }
+            }
+            if empty_squares > 0 {
+                fen.push_str(&empty_squares.to_string());
+            }
+            if rank > 0 {
+                fen.push('/');
+            }
+        }
+
+        fen.push(' ');
+        fen.push(match self.side_to_move {
+            SideToMove::White => 'w',
+            SideToMove::Black => 'b',
+        });
+
+        fen.push(' ');
+        if self.castling_rights.is_empty() {
+            fen.push('-');
+        } else {
+            if self.castling_rights.contains(CastlingRights::WHITE_KINGSIDE) {
+                fen.push('K');
+            }
+            if self.castling_rights.contains(CastlingRights::WHITE_QUEENSIDE) {
+                fen.push('Q');
+            }
+            if self.castling_rights.contains(CastlingRights::BLACK_KINGSIDE) {
+                fen.push('k');
+            }
+            if self.castling_rights.contains(CastlingRights::BLACK_QUEENSIDE) {
+                fen.push('q');
+            }
+        }
+
+        fen.push(' ');
+        if self.en_passant_square == Bitboard::EMPTY {
+            fen.push('-');
+        } else {
+            let square = self.en_passant_square.0.trailing_zeros() as usize;
+            let file = square % Bitboard::NUM_FILES;
+            let rank = square / Bitboard::NUM_FILES;
+            fen.push((b'a' + file as u8) as char);
+            fen.push((b'1' + rank as u8) as char);
+        }
+
+        fen.push(' ');
+        fen.push_str(&self.plies_since_pawn_move_or_capture.to_string());
+        fen.push(' ');
+        fen.push_str(&self.move_count.to_string());
+
+        fen
+    }
+
+    fn parse_piece_placement(pos: &mut Position, piece_placement: &str) -> Result<(), FenError> {
+        let ranks: Vec<&str> = piece_placement.split('/').collect();
+        if ranks.len() != Bitboard::NUM_RANKS {
+            return Err(FenError::WrongRankCount(ranks.len()));
+        }
+
+        for (rank_from_top, rank_str) in ranks.iter().enumerate() {
+            let rank = Bitboard::NUM_RANKS - 1 - rank_from_top;
+            let mut file = 0;
+            for c in rank_str.chars() {
+                if let Some(empty_squares) = c.to_digit(10) {
+                    file += empty_squares as usize;
+                    continue;
+                }
+                if file >= Bitboard::NUM_FILES {
+                    return Err(FenError::WrongFileCount(file, (*rank_str).to_owned()));
+                }
+                let square = Bitboard::to_square(rank, file);
+                let square_bb = Bitboard(0x1 << square);
+                let piece = Self::piece_from_ascii(c).ok_or(FenError::InvalidPiece(c))?;
+                match piece {
+                    Piece::WhitePawn | Piece::BlackPawn => pos.pawns = pos.pawns | square_bb,
+                    Piece::WhiteKnight | Piece::BlackKnight => pos.knights = pos.knights | square_bb,
+                    Piece::WhiteBishop | Piece::BlackBishop => pos.bishops = pos.bishops | square_bb,
+                    Piece::WhiteRook | Piece::BlackRook => pos.rooks = pos.rooks | square_bb,
+                    Piece::WhiteQueen | Piece::BlackQueen => pos.queens = pos.queens | square_bb,
+                    Piece::WhiteKing | Piece::BlackKing => pos.kings = pos.kings | square_bb,
+                }
+                match piece {
+                    Piece::WhitePawn
+                    | Piece::WhiteKnight
+                    | Piece::WhiteBishop
+                    | Piece::WhiteRook
+                    | Piece::WhiteQueen
+                    | Piece::WhiteKing => pos.white_pieces = pos.white_pieces | square_bb,
+                    _ => pos.black_pieces = pos.black_pieces | square_bb,
+                }
+                file += 1;
+            }
+            if file != Bitboard::NUM_FILES {
+                return Err(FenError::WrongFileCount(file, (*rank_str).to_owned()));
+            }
+        }
+        Ok(())
+    }
+
+    fn piece_from_ascii(c: char) -> Option<Piece> {
+        match c {
+            'P' => Some(Piece::WhitePawn),
+            'N' => Some(Piece::WhiteKnight),
+            'B' => Some(Piece::WhiteBishop),
+            'R' => Some(Piece::WhiteRook),
+            'Q' => Some(Piece::WhiteQueen),
+            'K' => Some(Piece::WhiteKing),
+            'p' => Some(Piece::BlackPawn),
+            'n' => Some(Piece::BlackKnight),
+            'b' => Some(Piece::BlackBishop),
+            'r' => Some(Piece::BlackRook),
+            'q' => Some(Piece::BlackQueen),
+            'k' => Some(Piece::BlackKing),
+            _ => None,
+        }
+    }
+
+    fn parse_side_to_move(side_to_move: &str) -> Result<SideToMove, FenError> {
+        match side_to_move {
+            "w" => Ok(SideToMove::White),
+            "b" => Ok(SideToMove::Black),
+            _ => Err(FenError::InvalidSideToMove(side_to_move.to_owned())),
+        }
+    }
+
+    fn parse_castling_rights(castling_rights: &str) -> Result<CastlingRights, FenError> {
+        if castling_rights == "-" {
+            return Ok(CastlingRights::empty());
+        }
+        let mut rights = CastlingRights::empty();
+        for c in castling_rights.chars() {
+            rights |= match c {
+                'K' => CastlingRights::WHITE_KINGSIDE,
+                'Q' => CastlingRights::WHITE_QUEENSIDE,
+                'k' => CastlingRights::BLACK_KINGSIDE,
+                'q' => CastlingRights::BLACK_QUEENSIDE,
+                _ => return Err(FenError::InvalidCastlingRights(castling_rights.to_owned())),
+            };
+        }
+        Ok(rights)
+    }
+
+    fn parse_en_passant_square(en_passant_square: &str) -> Result<Bitboard, FenError> {
+        if en_passant_square == "-" {
+            return Ok(Bitboard::EMPTY);
+        }
+        let chars: Vec<char> = en_passant_square.chars().collect();
+        if chars.len() != 2 {
+            return Err(FenError::InvalidEnPassantSquare(
+                en_passant_square.to_owned(),
+            ));
+        }
+        let file = chars[0] as i32 - 'a' as i32;
+        let rank = chars[1] as i32 - '1' as i32;
+        if !(0..Bitboard::NUM_FILES as i32).contains(&file)
+            || !(0..Bitboard::NUM_RANKS as i32).contains(&rank)
+        {
+            return Err(FenError::InvalidEnPassantSquare(
+                en_passant_square.to_owned(),
+            ));
+        }
+        let square = Bitboard::to_square(rank as usize, file as usize);
+        Ok(Bitboard(0x1 << square))
+    }
+
+    fn validate(&self) -> Result<(), FenError> {
+        let white_kings = (self.kings & self.white_pieces).0.count_ones();
+        let black_kings = (self.kings & self.black_pieces).0.count_ones();
+        if white_kings == 0 {
+            return Err(FenError::MissingKing(SideToMove::White));
+        }
+        if white_kings > 1 {
+            return Err(FenError::MultipleKings(SideToMove::White));
+        }
+        if black_kings == 0 {
+            return Err(FenError::MissingKing(SideToMove::Black));
+        }
+        if black_kings > 1 {
+            return Err(FenError::MultipleKings(SideToMove::Black));
+        }
+        if self.pawns & (Bitboard::RANK_1 | Bitboard::RANK_8) != Bitboard::EMPTY {
+            return Err(FenError::PawnOnBackRank);
+        }
+        Ok(())
+    }
 }
 
 impl fmt::Display for Position {
@@ -162,4 +610,119 @@ mod tests {
         ";
         assert_eq!(expected_str, format!("{}", Position::initial()));
     }
+
+    #[test]
+    fn zobrist_key_matches_across_construction_paths() {
+        let initial = Position::initial();
+        let from_fen =
+            Position::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1")
+                .unwrap();
+        assert_eq!(initial.zobrist_key(), from_fen.zobrist_key());
+    }
+
+    #[test]
+    fn toggle_piece_key_is_its_own_inverse() {
+        let mut pos = Position::initial();
+        let before = pos.zobrist_key();
+        pos.toggle_piece_key(Piece::WhitePawn, Bitboard::IDX_A2);
+        assert_ne!(before, pos.zobrist_key());
+        pos.toggle_piece_key(Piece::WhitePawn, Bitboard::IDX_A2);
+        assert_eq!(before, pos.zobrist_key());
+    }
+
+    #[test]
+    fn toggle_side_to_move_key_is_its_own_inverse() {
+        let mut pos = Position::initial();
+        let before = pos.zobrist_key();
+        pos.toggle_side_to_move_key();
+        assert_ne!(before, pos.zobrist_key());
+        pos.toggle_side_to_move_key();
+        assert_eq!(before, pos.zobrist_key());
+    }
+
+    #[test]
+    fn toggle_castling_rights_key_is_its_own_inverse() {
+        let mut pos = Position::initial();
+        let before = pos.zobrist_key();
+        pos.toggle_castling_rights_key(CastlingRights::WHITE_KINGSIDE);
+        assert_ne!(before, pos.zobrist_key());
+        pos.toggle_castling_rights_key(CastlingRights::WHITE_KINGSIDE);
+        assert_eq!(before, pos.zobrist_key());
+    }
+
+    #[test]
+    fn toggle_en_passant_square_key_is_its_own_inverse() {
+        let mut pos = Position::initial();
+        let before = pos.zobrist_key();
+        pos.toggle_en_passant_square_key(Bitboard::D6);
+        assert_ne!(before, pos.zobrist_key());
+        pos.toggle_en_passant_square_key(Bitboard::D6);
+        assert_eq!(before, pos.zobrist_key());
+    }
+
+    #[test]
+    fn zobrist_key_differs_for_different_positions() {
+        let initial = Position::initial();
+        let shifted =
+            Position::from_fen("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1")
+                .unwrap();
+        assert_ne!(initial.zobrist_key(), shifted.zobrist_key());
+    }
+
+    #[test]
+    fn from_fen_initial_position() {
+        let fen = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+        let pos = Position::from_fen(fen).unwrap();
+        assert_eq!(format!("{}", Position::initial()), format!("{}", pos));
+        assert_eq!(SideToMove::White, pos.side_to_move);
+        assert_eq!(
+            CastlingRights::WHITE_BOTH | CastlingRights::BLACK_BOTH,
+            pos.castling_rights
+        );
+        assert_eq!(Bitboard::EMPTY, pos.en_passant_square);
+        assert_eq!(0, pos.plies_since_pawn_move_or_capture);
+        assert_eq!(1, pos.move_count);
+    }
+
+    #[test]
+    fn from_fen_en_passant_and_partial_castling_rights() {
+        let fen = "rnbqkbnr/ppp1pppp/8/3pP3/8/8/PPPP1PPP/RNBQKBNR w Kq d6 0 3";
+        let pos = Position::from_fen(fen).unwrap();
+        assert_eq!(Bitboard::D6, pos.en_passant_square);
+        assert_eq!(
+            CastlingRights::WHITE_KINGSIDE | CastlingRights::BLACK_QUEENSIDE,
+            pos.castling_rights
+        );
+        assert_eq!(0, pos.plies_since_pawn_move_or_capture);
+        assert_eq!(3, pos.move_count);
+    }
+
+    #[test]
+    fn from_fen_rejects_malformed_input() {
+        assert_eq!(
+            Some(FenError::WrongFieldCount(5)),
+            Position::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq -").err()
+        );
+        assert_eq!(
+            Some(FenError::MissingKing(SideToMove::Black)),
+            Position::from_fen("rnbq1bnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1").err()
+        );
+        assert_eq!(
+            Some(FenError::PawnOnBackRank),
+            Position::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/PNBQKBNR w KQkq - 0 1").err()
+        );
+    }
+
+    #[test]
+    fn to_fen_round_trip() {
+        let fens = [
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "rnbqkbnr/ppp1pppp/8/3pP3/8/8/PPPP1PPP/RNBQKBNR w Kq d6 0 3",
+            "4k3/8/8/8/8/8/8/4K2R w K - 12 34",
+        ];
+        for fen in fens {
+            let pos = Position::from_fen(fen).unwrap();
+            assert_eq!(fen, pos.to_fen());
+        }
+    }
 }
@@ -0,0 +1,257 @@
+use eval::eval::Score;
+use movegen::move_generator::MoveGenerator;
+use movegen::piece::Piece;
+use movegen::position::Position;
+use movegen::position_history::PositionHistory;
+use movegen::r#move::{Move, MoveList};
+use movegen::side::Side;
+use search::search::Search;
+use std::io::{self, BufRead, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+const ENGINE_NAME: &str = "chess";
+const ENGINE_AUTHOR: &str = "FitzOReilly";
+
+/// Search this many plies ahead when `go` gives no depth/time bound at all.
+const DEFAULT_MAX_DEPTH: usize = 64;
+
+/// Front-end that drives [`Search`] over the Universal Chess Interface, so
+/// the engine can be plugged into any UCI-speaking GUI or bot harness.
+pub struct Uci {
+    pos_history: PositionHistory,
+}
+
+impl Uci {
+    pub fn new() -> Self {
+        Uci {
+            pos_history: PositionHistory::new(Position::initial()),
+        }
+    }
+
+    /// Reads UCI commands from stdin until `quit` or end of input, writing
+    /// responses to stdout.
+    pub fn run(&mut self) {
+        let stdin = io::stdin();
+        for line in stdin.lock().lines() {
+            let line = match line {
+                Ok(line) => line,
+                Err(_) => break,
+            };
+            if !self.handle_command(&line) {
+                break;
+            }
+        }
+    }
+
+    /// Handles one line of input. Returns `false` once `quit` is received.
+    fn handle_command(&mut self, command: &str) -> bool {
+        let mut tokens = command.split_whitespace();
+        match tokens.next() {
+            Some("uci") => {
+                println!("id name {}", ENGINE_NAME);
+                println!("id author {}", ENGINE_AUTHOR);
+                println!("uciok");
+            }
+            Some("isready") => println!("readyok"),
+            Some("ucinewgame") => self.pos_history = PositionHistory::new(Position::initial()),
+            Some("position") => self.handle_position(tokens),
+            Some("go") => self.handle_go(tokens),
+            Some("quit") => return false,
+            _ => {}
+        }
+        let _ = io::stdout().flush();
+        true
+    }
+
+    fn handle_position<'a>(&mut self, mut tokens: impl Iterator<Item = &'a str>) {
+        let pos = match tokens.next() {
+            Some("startpos") => Position::initial(),
+            Some("fen") => {
+                let fen_fields: Vec<&str> =
+                    tokens.by_ref().take_while(|&token| token != "moves").collect();
+                match Position::from_fen(&fen_fields.join(" ")) {
+                    Ok(pos) => pos,
+                    Err(_) => return,
+                }
+            }
+            _ => return,
+        };
+
+        self.pos_history = PositionHistory::new(pos);
+        for token in tokens {
+            if token == "moves" {
+                continue;
+            }
+            match move_from_uci(self.pos_history.current_pos(), token) {
+                Some(m) => self.pos_history.do_move(m),
+                None => break,
+            }
+        }
+    }
+
+    fn handle_go<'a>(&mut self, mut tokens: impl Iterator<Item = &'a str>) {
+        let mut wtime = None;
+        let mut btime = None;
+        let mut winc = None;
+        let mut binc = None;
+        let mut movetime = None;
+        let mut max_depth = None;
+
+        while let Some(token) = tokens.next() {
+            let value = tokens.next().and_then(|v| v.parse::<u64>().ok());
+            match token {
+                "wtime" => wtime = value,
+                "btime" => btime = value,
+                "winc" => winc = value,
+                "binc" => binc = value,
+                "movetime" => movetime = value,
+                "depth" => max_depth = value.map(|v| v as usize),
+                _ => {}
+            }
+        }
+
+        let side_to_move = self.pos_history.current_pos().side_to_move();
+        let budget = TimeManager::budget(side_to_move, wtime, btime, winc, binc, movetime);
+        let max_depth = max_depth.unwrap_or(DEFAULT_MAX_DEPTH);
+
+        let stop = Arc::new(AtomicBool::new(false));
+        if let Some(budget) = budget {
+            let stop_for_timer = Arc::clone(&stop);
+            thread::spawn(move || {
+                thread::sleep(budget);
+                stop_for_timer.store(true, Ordering::Relaxed);
+            });
+        }
+
+        let result = Search::iterative_deepening(
+            &mut self.pos_history,
+            max_depth,
+            &stop,
+            |depth, score, pv| {
+                // `score` is White-positive; UCI wants it from the side to
+                // move's own perspective.
+                let relative_score = match side_to_move {
+                    Side::White => score,
+                    Side::Black => -score,
+                };
+                println!(
+                    "info depth {} score {} pv {}",
+                    depth,
+                    format_score(relative_score),
+                    pv_to_uci(pv)
+                );
+            },
+        );
+
+        // There is no searched move to report for `go depth 0` (no result)
+        // or when the root was scored without searching a move, a draw by
+        // rule or no legal moves (the PV starts with `Move::NULL`). Then
+        // this falls back to the first legal move; `bestmove 0000` (no
+        // legal move at all) is reserved for a position that's actually over.
+        let best_move = result
+            .and_then(|(_, pv)| pv.iter().next().copied())
+            .filter(|m| *m != Move::NULL)
+            .or_else(|| {
+                let mut move_list = MoveList::new();
+                MoveGenerator::generate_moves(&mut move_list, self.pos_history.current_pos());
+                move_list.iter().next().copied()
+            });
+
+        match best_move {
+            Some(m) => println!("bestmove {}", move_to_uci(m)),
+            None => println!("bestmove 0000"),
+        }
+    }
+}
+
+impl Default for Uci {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Allocates a per-move time budget from the remaining clock, assuming
+/// roughly `EXPECTED_MOVES_LEFT` moves are still to be played. `movetime`,
+/// when given, overrides the clock entirely (a fixed-time search).
+struct TimeManager;
+
+impl TimeManager {
+    const EXPECTED_MOVES_LEFT: u64 = 40;
+
+    fn budget(
+        side_to_move: Side,
+        wtime: Option<u64>,
+        btime: Option<u64>,
+        winc: Option<u64>,
+        binc: Option<u64>,
+        movetime: Option<u64>,
+    ) -> Option<Duration> {
+        if let Some(movetime) = movetime {
+            return Some(Duration::from_millis(movetime));
+        }
+
+        let (time_left, increment) = match side_to_move {
+            Side::White => (wtime, winc.unwrap_or(0)),
+            Side::Black => (btime, binc.unwrap_or(0)),
+        };
+        time_left.map(|time_left| {
+            let millis = time_left / Self::EXPECTED_MOVES_LEFT + increment;
+            Duration::from_millis(millis)
+        })
+    }
+}
+
+/// Formats a side-to-move-relative score as a UCI `score` field: `mate <n>`
+/// (`n` moves away, negative if it's the side to move being mated) for a
+/// forced mate, `cp <n>` otherwise.
+fn format_score(score: Score) -> String {
+    match Search::mate_distance_in_moves(score) {
+        Some(moves) => format!("mate {}", moves),
+        None => format!("cp {}", score),
+    }
+}
+
+fn pv_to_uci(pv: &MoveList) -> String {
+    pv.iter()
+        .map(|m| move_to_uci(*m))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Formats a move in UCI long algebraic notation, e.g. `e2e4` or, for a
+/// promotion, `e7e8q`.
+fn move_to_uci(m: Move) -> String {
+    let mut uci = format!("{}{}", square_to_uci(m.from()), square_to_uci(m.to()));
+    if let Some(piece) = m.promotion_piece() {
+        uci.push(promotion_char(piece));
+    }
+    uci
+}
+
+/// Parses a UCI long algebraic move against the legal moves of `pos`,
+/// rather than decoding the from/to/promotion fields by hand, so an
+/// unreachable or otherwise illegal move is rejected for free.
+fn move_from_uci(pos: &Position, token: &str) -> Option<Move> {
+    let mut move_list = MoveList::new();
+    MoveGenerator::generate_moves(&mut move_list, pos);
+    move_list.iter().find(|m| move_to_uci(**m) == token).copied()
+}
+
+fn square_to_uci(square: usize) -> String {
+    let file = (square % 8) as u8;
+    let rank = (square / 8) as u8;
+    format!("{}{}", (b'a' + file) as char, (b'1' + rank) as char)
+}
+
+fn promotion_char(piece: Piece) -> char {
+    match piece {
+        Piece::WhiteKnight | Piece::BlackKnight => 'n',
+        Piece::WhiteBishop | Piece::BlackBishop => 'b',
+        Piece::WhiteRook | Piece::BlackRook => 'r',
+        Piece::WhiteQueen | Piece::BlackQueen => 'q',
+        _ => unreachable!("pawns only promote to a knight, bishop, rook or queen"),
+    }
+}